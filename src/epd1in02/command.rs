@@ -33,6 +33,10 @@ pub(crate) enum Command {
     ResolutionSetting = 0x61,
     VcmDcSetting = 0x82,
 
+    PartialWindow = 0x90,
+    PartialIn = 0x91,
+    PartialOut = 0x92,
+
     UnknownInit = 0xd2,
     PowerSaving = 0xE3,
 }
@@ -0,0 +1,58 @@
+//! Waveform lookup tables for the Epd1in02 controller.
+//!
+//! Each `LUT_*` table is a raw byte sequence uploaded to the controller's
+//! corresponding LUT register (see `Command::LutG0` and friends) and
+//! controls how a pixel's voltage is driven across refresh frames. Faster
+//! presets drive shorter phases and therefore leave more residual
+//! ghosting behind than `LUT_W1`/`LUT_B1` (the full, flash-refresh table).
+
+/// Full-refresh "white" waveform, used by the default (non-partial) update.
+#[rustfmt::skip]
+pub(crate) const LUT_W1: [u8; 42] = [
+    0x01, 0x0e, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x0c, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+];
+
+/// Full-refresh "black" waveform, used by the default (non-partial) update.
+#[rustfmt::skip]
+pub(crate) const LUT_B1: [u8; 42] = [
+    0x01, 0x0e, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x0c, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+];
+
+/// **UNVERIFIED / EXPERIMENTAL:** medium-speed waveform, intended to be
+/// noticeably faster than `Normal` at the cost of more ghosting. Unlike
+/// `LUT_W1`/`LUT_B1`, this table is not sourced from a UC8175 datasheet or
+/// a known-good reference driver — it has not been validated against real
+/// hardware. Pushed to both `LutG0` and `LutG1` if selected. Do not rely on
+/// this for production use until it's been confirmed against a datasheet
+/// or tested on real panels; using an incorrect waveform can cause visible
+/// ghosting or, over repeated use, panel damage.
+#[rustfmt::skip]
+pub(crate) const LUT_MEDIUM: [u8; 42] = [
+    0x01, 0x06, 0x06, 0x01, 0x01, 0x01, 0x01, 0x04, 0x04, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+];
+
+/// **UNVERIFIED / EXPERIMENTAL:** fast waveform, intended for rapid partial
+/// updates (clocks, counters) at the cost of the most residual ghosting of
+/// the four presets. Unlike `LUT_W1`/`LUT_B1`, this table is not sourced
+/// from a UC8175 datasheet or a known-good reference driver — it has not
+/// been validated against real hardware. Pushed to both `LutG0` and
+/// `LutG1` if selected. Do not rely on this for production use until it's
+/// been confirmed against a datasheet or tested on real panels; using an
+/// incorrect waveform can cause visible ghosting or, over repeated use,
+/// panel damage.
+#[rustfmt::skip]
+pub(crate) const LUT_FAST: [u8; 42] = [
+    0x01, 0x03, 0x03, 0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+];
@@ -0,0 +1,270 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle, Pixel};
+
+use crate::color::Color;
+
+use super::{DEFAULT_BACKGROUND_COLOR, HEIGHT, NUM_DISPLAY_BITS, WIDTH};
+
+/// Orientation the [`Display1in02`] buffer is drawn in.
+///
+/// The panel is a tall 80x128 portrait panel; rotating lets callers mount
+/// it sideways and draw in landscape without pre-rotating their bitmaps.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DisplayRotation {
+    /// No rotation: the panel's native 80 (w) x 128 (h) orientation.
+    Rotate0,
+    /// Rotated 90 degrees clockwise; reports as 128 (w) x 80 (h).
+    Rotate90,
+    /// Rotated 180 degrees; still reports as 80 (w) x 128 (h).
+    Rotate180,
+    /// Rotated 270 degrees clockwise; reports as 128 (w) x 80 (h).
+    Rotate270,
+}
+
+impl Default for DisplayRotation {
+    fn default() -> Self {
+        DisplayRotation::Rotate0
+    }
+}
+
+/// Maps a coordinate in the caller's (rotated) view back onto the panel's
+/// native, unrotated buffer.
+///
+/// `x`/`y` are expected to already be within the rotated canvas reported by
+/// `size()`, but embedded-graphics primitives (`Circle`, `Line`, ...)
+/// routinely emit points that stray outside a `DrawTarget`'s bounding box
+/// and rely on it clipping them silently, so the subtractions here
+/// saturate instead of underflowing/panicking on such input.
+fn rotation(x: u32, y: u32, rotation: DisplayRotation) -> (u32, u32) {
+    match rotation {
+        DisplayRotation::Rotate0 => (x, y),
+        DisplayRotation::Rotate90 => (WIDTH.saturating_sub(1).saturating_sub(y), x),
+        DisplayRotation::Rotate180 => (
+            WIDTH.saturating_sub(1).saturating_sub(x),
+            HEIGHT.saturating_sub(1).saturating_sub(y),
+        ),
+        DisplayRotation::Rotate270 => (y, HEIGHT.saturating_sub(1).saturating_sub(x)),
+    }
+}
+
+/// Packed 1bpp frame buffer for the Epd1in02, addressable through
+/// embedded-graphics.
+pub struct Display1in02 {
+    buffer: [u8; NUM_DISPLAY_BITS as usize],
+    rotation: DisplayRotation,
+}
+
+impl Default for Display1in02 {
+    fn default() -> Self {
+        Display1in02 {
+            buffer: [DEFAULT_BACKGROUND_COLOR.get_byte_value(); NUM_DISPLAY_BITS as usize],
+            rotation: DisplayRotation::default(),
+        }
+    }
+}
+
+impl Display1in02 {
+    /// Returns the raw packed buffer for the whole panel, ready to hand to
+    /// `Epd1in02::update_frame` (or to `update_partial_frame` for a window
+    /// covering the whole panel).
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Sets the orientation pixels are drawn in.
+    pub fn set_rotation(&mut self, rotation: DisplayRotation) {
+        self.rotation = rotation;
+    }
+
+    /// Returns the orientation pixels are currently drawn in.
+    pub fn rotation(&self) -> DisplayRotation {
+        self.rotation
+    }
+
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x >= WIDTH || y >= HEIGHT {
+            return;
+        }
+        let index = (y * WIDTH + x) / 8;
+        let bit = 0x80 >> (x % 8);
+        if color == Color::Black {
+            self.buffer[index as usize] &= !bit;
+        } else {
+            self.buffer[index as usize] |= bit;
+        }
+    }
+
+    /// Fills `[x0, x1) x [y0, y1)` (already in native, unrotated buffer
+    /// coordinates) with `color`, writing whole bytes for byte-aligned
+    /// spans instead of setting one bit at a time, and falling back to
+    /// `set_pixel` only for the ragged left/right edge of each row.
+    fn fill_native_rect(&mut self, x0: u32, y0: u32, x1: u32, y1: u32, color: Color) {
+        let fill_byte = if color == Color::Black { 0x00 } else { 0xFF };
+
+        for y in y0..y1 {
+            let row = y * WIDTH;
+            let mut x = x0;
+            while x < x1 {
+                if x % 8 == 0 && x1 - x >= 8 {
+                    self.buffer[((row + x) / 8) as usize] = fill_byte;
+                    x += 8;
+                } else {
+                    self.set_pixel(x, y, color);
+                    x += 1;
+                }
+            }
+        }
+    }
+}
+
+impl OriginDimensions for Display1in02 {
+    fn size(&self) -> Size {
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => Size::new(WIDTH, HEIGHT),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => Size::new(HEIGHT, WIDTH),
+        }
+    }
+}
+
+impl DrawTarget for Display1in02 {
+    type Color = Color;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Color>>,
+    {
+        let canvas = self.size();
+        for Pixel(point, color) in pixels.into_iter() {
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+            let (px, py) = (point.x as u32, point.y as u32);
+            if px >= canvas.width || py >= canvas.height {
+                continue;
+            }
+            let (x, y) = rotation(px, py, self.rotation);
+            self.set_pixel(x, y, color);
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Color) -> Result<(), Self::Error> {
+        if area.size.width == 0 || area.size.height == 0 {
+            return Ok(());
+        }
+
+        // Rectangles with a negative-coordinate corner are rare enough
+        // (partly or fully off-panel) that they aren't worth special-casing
+        // here.
+        if area.top_left.x < 0 || area.top_left.y < 0 {
+            return self.draw_iter(
+                area.points().map(|point| Pixel(point, color)),
+            );
+        }
+
+        // Clip to the rotated canvas, same as `draw_iter` does per-pixel,
+        // before mapping into native space: a rectangle is free to extend
+        // past `bounding_box()` (e.g. `clear()` with an oversized rect),
+        // and `rotation()`'s saturating subtraction only guarantees no
+        // panic, not a sensible result for out-of-canvas input.
+        let canvas = self.size();
+        if area.top_left.x as u32 >= canvas.width || area.top_left.y as u32 >= canvas.height {
+            return Ok(());
+        }
+        let x0 = (area.top_left.x as u32).min(canvas.width - 1);
+        let y0 = (area.top_left.y as u32).min(canvas.height - 1);
+        let x1 = (x0 + area.size.width - 1).min(canvas.width - 1);
+        let y1 = (y0 + area.size.height - 1).min(canvas.height - 1);
+
+        // A rectangle stays a rectangle under any multiple-of-90-degree
+        // rotation, so mapping the two opposite corners into native buffer
+        // space and taking their bounding box is enough.
+        let (nx_a, ny_a) = rotation(x0, y0, self.rotation);
+        let (nx_b, ny_b) = rotation(x1, y1, self.rotation);
+
+        let nx0 = nx_a.min(nx_b).min(WIDTH - 1);
+        let nx1 = nx_a.max(nx_b).min(WIDTH - 1);
+        let ny0 = ny_a.min(ny_b).min(HEIGHT - 1);
+        let ny1 = ny_a.max(ny_b).min(HEIGHT - 1);
+
+        self.fill_native_rect(nx0, ny0, nx1 + 1, ny1 + 1, color);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATIONS: [DisplayRotation; 4] = [
+        DisplayRotation::Rotate0,
+        DisplayRotation::Rotate90,
+        DisplayRotation::Rotate180,
+        DisplayRotation::Rotate270,
+    ];
+
+    fn canvas_size(rotation: DisplayRotation) -> (u32, u32) {
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (WIDTH, HEIGHT),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (HEIGHT, WIDTH),
+        }
+    }
+
+    /// `rotation()` maps a point from the rotated canvas back onto the
+    /// native buffer; for any rotation, its four canvas corners must land
+    /// exactly on the native panel's four corners (in some order), since a
+    /// rectangle stays a rectangle under any multiple-of-90-degree rotation.
+    #[test]
+    fn rotation_maps_canvas_corners_onto_native_corners() {
+        let mut native_corners = [
+            (0, 0),
+            (WIDTH - 1, 0),
+            (0, HEIGHT - 1),
+            (WIDTH - 1, HEIGHT - 1),
+        ];
+        native_corners.sort();
+
+        for r in ROTATIONS {
+            let (cw, ch) = canvas_size(r);
+            let mut mapped: [(u32, u32); 4] = [
+                rotation(0, 0, r),
+                rotation(cw - 1, 0, r),
+                rotation(0, ch - 1, r),
+                rotation(cw - 1, ch - 1, r),
+            ];
+            mapped.sort();
+            assert_eq!(mapped, native_corners, "{:?} corners didn't round-trip", r);
+        }
+    }
+
+    /// `Rotate0` and `Rotate180` share the native panel's own dimensions as
+    /// their canvas, so applying either mapping twice returns the original
+    /// point.
+    #[test]
+    fn rotate0_and_rotate180_are_self_inverse() {
+        for r in [DisplayRotation::Rotate0, DisplayRotation::Rotate180] {
+            for (x, y) in [(0, 0), (40, 64), (WIDTH - 1, HEIGHT - 1)] {
+                let (nx, ny) = rotation(x, y, r);
+                assert_eq!(rotation(nx, ny, r), (x, y));
+            }
+        }
+    }
+
+    /// `fill_solid`'s whole-byte fast path must leave the buffer identical
+    /// to filling the same area one pixel at a time via `draw_iter`, even
+    /// for a ragged rectangle whose edges don't land on a byte boundary.
+    #[test]
+    fn fill_solid_matches_pixel_by_pixel_draw_iter() {
+        let area = Rectangle::new(Point::new(3, 5), Size::new(13, 7));
+
+        let mut via_fill_solid = Display1in02::default();
+        via_fill_solid.fill_solid(&area, Color::Black).unwrap();
+
+        let mut via_draw_iter = Display1in02::default();
+        via_draw_iter
+            .draw_iter(area.points().map(|point| Pixel(point, Color::Black)))
+            .unwrap();
+
+        assert_eq!(via_fill_solid.buffer(), via_draw_iter.buffer());
+    }
+}
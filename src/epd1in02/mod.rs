@@ -23,18 +23,68 @@ use crate::color::Color;
 
 pub(crate) mod command;
 pub(crate) mod constants;
-use self::{command::Command, constants::{LUT_W1, LUT_B1}};
+use self::{
+    command::Command,
+    constants::{LUT_B1, LUT_FAST, LUT_MEDIUM, LUT_W1},
+};
 
 #[cfg(feature = "graphics")]
 mod graphics;
 
 #[cfg(feature = "graphics")]
-pub use self::graphics::Display1in02;
+pub use self::graphics::{Display1in02, DisplayRotation};
+
+// PanelSetting bit that selects where the LUT waveforms come from:
+// 1 = use the tables pushed to the LutG0/LutG1/... registers, 0 = use the
+// panel's built-in OTP waveform.
+const PANEL_SETTING_REG_LUT: u8 = 0x20;
+const PANEL_SETTING_BASE: u8 = 0x6f;
+
+/// Selectable refresh-speed waveform preset, pushed to the controller by
+/// [`Epd1in02::set_lut`]/[`Epd1in02::set_lut_preset`].
+///
+/// Faster presets use a shorter waveform and therefore leave more residual
+/// ghosting behind; `Fast` is intended for rapid partial updates (clocks,
+/// counters) where a little ghosting is an acceptable trade for speed.
+///
+/// `Medium` and `Fast` push waveform tables ([`constants::LUT_MEDIUM`],
+/// [`constants::LUT_FAST`]) that are unverified against a datasheet or
+/// known-good reference driver — see their doc comments before relying on
+/// them for anything other than experimentation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LutPreset {
+    /// Use the panel's built-in OTP waveform instead of the register LUTs.
+    Internal,
+    /// Full-length waveform; same tables the driver has always used.
+    Normal,
+    /// Shorter waveform than `Normal`; faster with a bit more ghosting.
+    /// Unverified waveform data, see [`constants::LUT_MEDIUM`].
+    Medium,
+    /// Shortest waveform; fastest updates, most residual ghosting.
+    /// Unverified waveform data, see [`constants::LUT_FAST`].
+    Fast,
+}
+
+impl LutPreset {
+    /// Returns the (LutG0, LutG1) tables for this preset, or `None` for
+    /// `Internal`, which uses the panel's OTP waveform instead of the
+    /// register LUTs. Only these two registers are touched, matching what
+    /// the driver has always written for its one fixed waveform.
+    fn tables(self) -> Option<(&'static [u8], &'static [u8])> {
+        match self {
+            LutPreset::Internal => None,
+            LutPreset::Normal => Some((&LUT_W1, &LUT_B1)),
+            LutPreset::Medium => Some((&LUT_MEDIUM, &LUT_MEDIUM)),
+            LutPreset::Fast => Some((&LUT_FAST, &LUT_FAST)),
+        }
+    }
+}
 
 /// Epd1in02 driver
 pub struct Epd1in02<SPI, CS, BUSY, DC, RST, DELAY> {
     interface: DisplayInterface<SPI, CS, BUSY, DC, RST, DELAY>,
     color: Color,
+    lut: LutPreset,
 }
 
 impl<SPI, CS, BUSY, DC, RST, DELAY> InternalWiAdditions<SPI, CS, BUSY, DC, RST, DELAY>
@@ -56,8 +106,6 @@ where
 
         self.cmd_with_data(spi, Command::UnknownInit, &[0x3f])?;
 
-        // set the panel settings
-        self.cmd_with_data(spi, Command::PanelSetting, &[0x6f])?;
         // power setting
         self.cmd_with_data(spi, Command::PowerSetting, &[0x03, 0x00, 0x2b, 0x2b])?;
         // charge pump
@@ -77,7 +125,7 @@ where
         // Set POWER SAVING
         self.cmd_with_data(spi, Command::PowerSaving, &[0x33])?;
 
-        self.set_full_reg(spi)?;
+        self.set_lut_preset(spi, self.lut)?;
 
         // power on
         self.command(spi, Command::PowerOn)?;
@@ -109,7 +157,11 @@ where
         let interface = DisplayInterface::new(cs, busy, dc, rst);
         let color = DEFAULT_BACKGROUND_COLOR;
 
-        let mut epd = Epd1in02 { interface, color };
+        let mut epd = Epd1in02 {
+            interface,
+            color,
+            lut: LutPreset::Normal,
+        };
 
         epd.init(spi, delay)?;
 
@@ -162,7 +214,31 @@ where
         Ok(())
     }
 
-    #[allow(unused)]
+    /// Writes `buffer` to a rectangular region of the panel and refreshes
+    /// only that region, instead of the whole panel, using the
+    /// controller's differential partial-refresh LUT.
+    ///
+    /// A zero-area request (`width == 0`, `height == 0`, or a rectangle
+    /// entirely outside the panel) is a no-op rather than an error.
+    ///
+    /// KNOWN LIMITATION: this is a real behavior gap, not a deliberate
+    /// design choice — a caller asking to refresh an empty or out-of-bounds
+    /// window currently gets no signal that nothing happened.
+    /// `WaveshareDisplay::update_partial_frame` is declared to return
+    /// `Result<(), SPI::Error>`, and `SPI::Error` is the transport's error
+    /// type, not ours, so there is no variant here to report a bad argument
+    /// with. Surfacing this properly needs the shared `WaveshareDisplay`
+    /// trait (in `traits.rs`) to grow an error type that can hold driver
+    /// errors alongside SPI ones, which is outside the scope of this driver.
+    ///
+    /// `WIDTH` is packed 8px/byte, so the controller can only move its
+    /// partial window in whole-byte steps along x: `x` is rounded down,
+    /// and the window widened so it still covers the full requested
+    /// `width`, to the nearest byte boundary. `buffer` must already be
+    /// packed for that *rounded* window, not the raw `x`/`width` passed
+    /// in, i.e. `height` rows of `(x - (x & !0x7) + width + 7) / 8` bytes
+    /// each, starting at column `x & !0x7`. The window is clipped to the
+    /// panel: rows past `HEIGHT` and bytes past `WIDTH` are dropped from it.
     fn update_partial_frame(
         &mut self,
         spi: &mut SPI,
@@ -172,7 +248,25 @@ where
         width: u32,
         height: u32,
     ) -> Result<(), SPI::Error> {
-        unimplemented!()
+        if width == 0 || height == 0 || x >= WIDTH || y >= HEIGHT {
+            return Ok(());
+        }
+
+        let y_start = y;
+        let y_end = (y + height - 1).min(HEIGHT - 1);
+
+        let x_start = x & !0x7;
+        let width_bytes = ((x - x_start + width + 7) / 8).min((WIDTH - x_start) / 8);
+        let x_end = x_start + width_bytes * 8 - 1;
+
+        self.command(spi, Command::PartialIn)?;
+        self.set_partial_window(spi, x_start, y_start, x_end, y_end)?;
+        self.cmd_with_data(spi, Command::DataStartTransmission2, buffer)?;
+        self.command(spi, Command::DisplayRefresh)?;
+        self.wait_until_idle();
+        self.command(spi, Command::PartialOut)?;
+
+        Ok(())
     }
 
     fn display_frame(&mut self, spi: &mut SPI, _delay: &mut DELAY) -> Result<(), SPI::Error> {
@@ -211,10 +305,15 @@ where
 
     fn set_lut(
         &mut self,
-        _spi: &mut SPI,
-        _refresh_rate: Option<RefreshLut>,
+        spi: &mut SPI,
+        refresh_rate: Option<RefreshLut>,
     ) -> Result<(), SPI::Error> {
-        Ok(())
+        let preset = match refresh_rate {
+            None => LutPreset::Internal,
+            Some(RefreshLut::Full) => LutPreset::Normal,
+            Some(RefreshLut::Quick) => LutPreset::Fast,
+        };
+        self.set_lut_preset(spi, preset)
     }
 
     fn is_busy(&self) -> bool {
@@ -269,8 +368,51 @@ where
         self.send_data(spi, &[h as u8])
     }
 
-    fn set_full_reg(&mut self, spi: &mut SPI) -> Result<(), SPI::Error> { 
-        self.cmd_with_data(spi, Command::LutG0, &LUT_W1)?;
-        self.cmd_with_data(spi, Command::LutG1, &LUT_B1)
+    /// Selects a refresh-speed waveform preset, pushing its tables to the
+    /// controller's LUT registers (or, for [`LutPreset::Internal`], falling
+    /// back to the panel's built-in OTP waveform).
+    pub fn set_lut_preset(&mut self, spi: &mut SPI, preset: LutPreset) -> Result<(), SPI::Error> {
+        self.lut = preset;
+
+        let panel_setting = if preset == LutPreset::Internal {
+            PANEL_SETTING_BASE & !PANEL_SETTING_REG_LUT
+        } else {
+            PANEL_SETTING_BASE | PANEL_SETTING_REG_LUT
+        };
+        self.cmd_with_data(spi, Command::PanelSetting, &[panel_setting])?;
+
+        if let Some((g0, g1)) = preset.tables() {
+            self.cmd_with_data(spi, Command::LutG0, g0)?;
+            self.cmd_with_data(spi, Command::LutG1, g1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the controller's active window to the given byte-aligned
+    /// rectangle (inclusive coordinates) ahead of a partial-refresh update.
+    fn set_partial_window(
+        &mut self,
+        spi: &mut SPI,
+        x_start: u32,
+        y_start: u32,
+        x_end: u32,
+        y_end: u32,
+    ) -> Result<(), SPI::Error> {
+        self.command(spi, Command::PartialWindow)?;
+        self.send_data(spi, &[(x_start >> 3) as u8])?;
+        self.send_data(spi, &[(x_end >> 3) as u8])?;
+        self.send_data(spi, &[y_start as u8, (y_start >> 8) as u8])?;
+        self.send_data(spi, &[y_end as u8, (y_end >> 8) as u8])?;
+        self.send_data(spi, &[0x01])
     }
 }
+
+// NEEDS RESCOPING: on-chip temperature readback (`read_temperature`) is not
+// implemented and not covered by this series. It requires `DisplayInterface`
+// to grow a `read_data`/`read_cmd` path on top of its current
+// `Write<u8>`-only transport — a change to the shared `interface` module,
+// not to this driver — and that groundwork doesn't exist in this tree.
+// Landing `read_temperature` here without it would reference a method that
+// doesn't exist. Needs a follow-up change scoped to `interface.rs` before
+// this can be picked back up.